@@ -1,6 +1,6 @@
 use crate::canvas::path::{arc, Arc, Path};
 
-use iced_native::{Point, Size};
+use iced_native::{Point, Size, Vector};
 use lyon::path::builder::{Build, FlatPathBuilder, PathBuilder, SvgBuilder};
 
 /// A [`Path`] builder.
@@ -11,6 +11,10 @@ use lyon::path::builder::{Build, FlatPathBuilder, PathBuilder, SvgBuilder};
 #[allow(missing_debug_implementations)]
 pub struct Builder {
     raw: lyon::path::builder::SvgPathBuilder<lyon::path::Builder>,
+    transforms: Vec<lyon::math::Transform>,
+    current: Point,
+    subpath_start: Point,
+    fill_rule: FillRule,
 }
 
 impl Builder {
@@ -20,24 +24,160 @@ impl Builder {
     pub fn new() -> Builder {
         Builder {
             raw: lyon::path::Path::builder().with_svg(),
+            transforms: Vec::new(),
+            current: Point::new(0.0, 0.0),
+            subpath_start: Point::new(0.0, 0.0),
+            fill_rule: FillRule::NonZero,
+        }
+    }
+
+    /// Sets the [`FillRule`] used to fill the [`Path`] built by this
+    /// [`Builder`].
+    ///
+    /// This is necessary to correctly fill self-intersecting paths and
+    /// paths with holes described by an outer contour plus one or more
+    /// reversed inner contours.
+    ///
+    /// [`FillRule`]: enum.FillRule.html
+    /// [`Path`]: struct.Path.html
+    /// [`Builder`]: struct.Builder.html
+    #[inline]
+    pub fn fill_rule(&mut self, rule: FillRule) {
+        self.fill_rule = rule;
+    }
+
+    /// Returns the current position of this [`Builder`], in the local
+    /// coordinate space (i.e. before any active [`push_transform`] is
+    /// applied).
+    ///
+    /// [`Builder`]: struct.Builder.html
+    /// [`push_transform`]: struct.Builder.html#method.push_transform
+    fn current_position(&self) -> Point {
+        self.current
+    }
+
+    /// Pushes an affine `Transform` onto this [`Builder`]'s transform
+    /// stack, combining it with any transform already active.
+    ///
+    /// Every point passed to [`move_to`], [`line_to`], [`bezier_curve_to`],
+    /// [`quadratic_curve_to`], or [`arc`] is mapped through the accumulated
+    /// transform until the matching [`pop_transform`]. This lets a shape be
+    /// authored once in local coordinates and instanced at different
+    /// positions, scales, or rotations.
+    ///
+    /// [`Builder`]: struct.Builder.html
+    /// [`move_to`]: struct.Builder.html#method.move_to
+    /// [`line_to`]: struct.Builder.html#method.line_to
+    /// [`bezier_curve_to`]: struct.Builder.html#method.bezier_curve_to
+    /// [`quadratic_curve_to`]: struct.Builder.html#method.quadratic_curve_to
+    /// [`arc`]: struct.Builder.html#method.arc
+    /// [`pop_transform`]: struct.Builder.html#method.pop_transform
+    pub fn push_transform(&mut self, transform: lyon::math::Transform) {
+        let accumulated = self
+            .transforms
+            .last()
+            .map(|current| transform.then(current))
+            .unwrap_or(transform);
+
+        self.transforms.push(accumulated);
+    }
+
+    /// Pops the transform most recently pushed with [`push_transform`],
+    /// reverting to the one that was active before it.
+    ///
+    /// [`push_transform`]: struct.Builder.html#method.push_transform
+    pub fn pop_transform(&mut self) {
+        let _ = self.transforms.pop();
+    }
+
+    /// Maps `point` through the transform currently active on this
+    /// [`Builder`], if any.
+    ///
+    /// [`Builder`]: struct.Builder.html
+    fn transform_point(&self, point: Point) -> Point {
+        match self.transforms.last() {
+            Some(transform) => {
+                let transformed = transform
+                    .transform_point(lyon::math::Point::new(point.x, point.y));
+
+                Point::new(transformed.x, transformed.y)
+            }
+            None => point,
+        }
+    }
+
+    /// Maps an ellipse's `radii` and `x_rotation` through the transform
+    /// currently active on this [`Builder`], if any, by transforming its
+    /// major and minor axis vectors and measuring the result.
+    ///
+    /// [`Builder`]: struct.Builder.html
+    fn transform_ellipse(&self, radii: Vector, x_rotation: f32) -> (Vector, f32) {
+        match self.transforms.last() {
+            Some(transform) => {
+                let major = transform.transform_vector(lyon::math::Vector::new(
+                    radii.x * x_rotation.cos(),
+                    radii.x * x_rotation.sin(),
+                ));
+                let minor = transform.transform_vector(lyon::math::Vector::new(
+                    -radii.y * x_rotation.sin(),
+                    radii.y * x_rotation.cos(),
+                ));
+
+                let radii = Vector::new(
+                    (major.x * major.x + major.y * major.y).sqrt(),
+                    (minor.x * minor.x + minor.y * minor.y).sqrt(),
+                );
+
+                (radii, major.y.atan2(major.x))
+            }
+            None => (radii, x_rotation),
         }
     }
 
     /// Moves the starting point of a new sub-path to the given `Point`.
     #[inline]
     pub fn move_to(&mut self, point: Point) {
+        self.current = point;
+        self.subpath_start = point;
+        let point = self.transform_point(point);
+
         let _ = self.raw.move_to(lyon::math::Point::new(point.x, point.y));
     }
 
+    /// Moves the starting point of a new sub-path by the given `Vector`
+    /// relative to the last point in the [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    #[inline]
+    pub fn rel_move_to(&mut self, to: Vector) {
+        let current = self.current_position();
+
+        self.move_to(Point::new(current.x + to.x, current.y + to.y));
+    }
+
     /// Connects the last point in the [`Path`] to the given `Point` with a
     /// straight line.
     ///
     /// [`Path`]: struct.Path.html
     #[inline]
     pub fn line_to(&mut self, point: Point) {
+        self.current = point;
+        let point = self.transform_point(point);
+
         let _ = self.raw.line_to(lyon::math::Point::new(point.x, point.y));
     }
 
+    /// Connects the last point in the [`Path`] to a point at the given
+    /// `Vector` displacement with a straight line.
+    ///
+    /// [`Path`]: struct.Path.html
+    #[inline]
+    pub fn rel_line_to(&mut self, to: Vector) {
+        let current = self.current_position();
+
+        self.line_to(Point::new(current.x + to.x, current.y + to.y));
+    }
+
     /// Adds an [`Arc`] to the [`Path`] from `start_angle` to `end_angle` in
     /// a clockwise direction.
     ///
@@ -58,6 +198,11 @@ impl Builder {
     pub fn arc_to(&mut self, a: Point, b: Point, radius: f32) {
         use lyon::{math, path};
 
+        self.current = b;
+
+        let a = self.transform_point(a);
+        let b = self.transform_point(b);
+
         let a = math::Point::new(a.x, a.y);
 
         if self.raw.current_position() != a {
@@ -72,6 +217,52 @@ impl Builder {
         );
     }
 
+    /// Adds a circular arc to the [`Path`] with the given control points and
+    /// radius, with both control points expressed as `Vector` displacements
+    /// relative to the last point in the [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn rel_arc_to(&mut self, a: Vector, b: Vector, radius: f32) {
+        let current = self.current_position();
+
+        self.arc_to(
+            Point::new(current.x + a.x, current.y + a.y),
+            Point::new(current.x + b.x, current.y + b.y),
+            radius,
+        );
+    }
+
+    /// Adds an elliptical arc to the [`Path`] from its last point to `to`,
+    /// following the SVG `A`/`a` command grammar.
+    ///
+    /// `radii` gives the ellipse's x and y radii, `x_rotation` rotates the
+    /// ellipse (in radians), and `large_arc`/`sweep` pick which of the four
+    /// candidate arcs connecting the current point to `to` is drawn.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn elliptical_arc_to(
+        &mut self,
+        radii: Vector,
+        x_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    ) {
+        use lyon::{math, path};
+
+        self.current = to;
+
+        let to = self.transform_point(to);
+        let (radii, x_rotation) = self.transform_ellipse(radii, x_rotation);
+
+        let _ = self.raw.arc_to(
+            math::Vector::new(radii.x, radii.y),
+            math::Angle::radians(x_rotation),
+            path::ArcFlags { large_arc, sweep },
+            math::Point::new(to.x, to.y),
+        );
+    }
+
     /// Adds an [`Ellipse`] to the [`Path`] using a clockwise direction.
     ///
     /// [`Ellipse`]: struct.Arc.html
@@ -87,11 +278,24 @@ impl Builder {
             sweep_angle: math::Angle::radians(arc.end_angle),
         };
 
-        let _ = self.raw.move_to(arc.sample(0.0));
+        let start = arc.sample(0.0);
+        self.current = Point::new(start.x, start.y);
+        self.subpath_start = self.current;
+        let start = self.transform_point(self.current);
+        let _ = self.raw.move_to(math::Point::new(start.x, start.y));
 
         arc.for_each_quadratic_bezier(&mut |curve| {
-            let _ = self.raw.quadratic_bezier_to(curve.ctrl, curve.to);
+            let ctrl = self.transform_point(Point::new(curve.ctrl.x, curve.ctrl.y));
+            let to = self.transform_point(Point::new(curve.to.x, curve.to.y));
+
+            let _ = self.raw.quadratic_bezier_to(
+                math::Point::new(ctrl.x, ctrl.y),
+                math::Point::new(to.x, to.y),
+            );
         });
+
+        let end = arc.sample(1.0);
+        self.current = Point::new(end.x, end.y);
     }
 
     /// Adds a cubic B??zier curve to the [`Path`] given its two control points
@@ -107,6 +311,12 @@ impl Builder {
     ) {
         use lyon::math;
 
+        self.current = to;
+
+        let control_a = self.transform_point(control_a);
+        let control_b = self.transform_point(control_b);
+        let to = self.transform_point(to);
+
         let _ = self.raw.cubic_bezier_to(
             math::Point::new(control_a.x, control_a.y),
             math::Point::new(control_b.x, control_b.y),
@@ -114,6 +324,27 @@ impl Builder {
         );
     }
 
+    /// Adds a cubic B??zier curve to the [`Path`] given its two control
+    /// points and its end point, all expressed as `Vector` displacements
+    /// relative to the last point in the [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    #[inline]
+    pub fn rel_bezier_curve_to(
+        &mut self,
+        control_a: Vector,
+        control_b: Vector,
+        to: Vector,
+    ) {
+        let current = self.current_position();
+
+        self.bezier_curve_to(
+            Point::new(current.x + control_a.x, current.y + control_a.y),
+            Point::new(current.x + control_b.x, current.y + control_b.y),
+            Point::new(current.x + to.x, current.y + to.y),
+        );
+    }
+
     /// Adds a quadratic B??zier curve to the [`Path`] given its control point
     /// and its end point.
     ///
@@ -122,12 +353,32 @@ impl Builder {
     pub fn quadratic_curve_to(&mut self, control: Point, to: Point) {
         use lyon::math;
 
+        self.current = to;
+
+        let control = self.transform_point(control);
+        let to = self.transform_point(to);
+
         let _ = self.raw.quadratic_bezier_to(
             math::Point::new(control.x, control.y),
             math::Point::new(to.x, to.y),
         );
     }
 
+    /// Adds a quadratic B??zier curve to the [`Path`] given its control point
+    /// and its end point, both expressed as `Vector` displacements relative
+    /// to the last point in the [`Path`].
+    ///
+    /// [`Path`]: struct.Path.html
+    #[inline]
+    pub fn rel_quadratic_curve_to(&mut self, control: Vector, to: Vector) {
+        let current = self.current_position();
+
+        self.quadratic_curve_to(
+            Point::new(current.x + control.x, current.y + control.y),
+            Point::new(current.x + to.x, current.y + to.y),
+        );
+    }
+
     /// Adds a rectangle to the [`Path`] given its top-left corner coordinate
     /// and its `Size`.
     ///
@@ -144,6 +395,45 @@ impl Builder {
         self.close();
     }
 
+    /// Adds a rounded rectangle to the [`Path`] given its top-left corner
+    /// coordinate, its `Size`, and the `radius` of its four corners.
+    ///
+    /// `radius` is clamped to at most half of the smaller of `size.width`
+    /// and `size.height`, so that opposite corners never overlap.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn rounded_rectangle(&mut self, top_left: Point, size: Size, radius: f32) {
+        let radius = radius.min(size.width / 2.0).min(size.height / 2.0);
+
+        let top_right = Point::new(top_left.x + size.width, top_left.y);
+        let bottom_right =
+            Point::new(top_left.x + size.width, top_left.y + size.height);
+        let bottom_left = Point::new(top_left.x, top_left.y + size.height);
+
+        self.move_to(Point::new(top_left.x + radius, top_left.y));
+        self.line_to(Point::new(top_right.x - radius, top_right.y));
+        self.elliptical_arc_to(
+            Vector::new(radius, radius), 0.0, false, true,
+            Point::new(top_right.x, top_right.y + radius),
+        );
+        self.line_to(Point::new(bottom_right.x, bottom_right.y - radius));
+        self.elliptical_arc_to(
+            Vector::new(radius, radius), 0.0, false, true,
+            Point::new(bottom_right.x - radius, bottom_right.y),
+        );
+        self.line_to(Point::new(bottom_left.x + radius, bottom_left.y));
+        self.elliptical_arc_to(
+            Vector::new(radius, radius), 0.0, false, true,
+            Point::new(bottom_left.x, bottom_left.y - radius),
+        );
+        self.line_to(Point::new(top_left.x, top_left.y + radius));
+        self.elliptical_arc_to(
+            Vector::new(radius, radius), 0.0, false, true,
+            Point::new(top_left.x + radius, top_left.y),
+        );
+        self.close();
+    }
+
     /// Adds a circle to the [`Path`] given its center coordinate and its
     /// radius.
     ///
@@ -161,10 +451,15 @@ impl Builder {
     /// Closes the current sub-path in the [`Path`] with a straight line to
     /// the starting point.
     ///
+    /// This also moves the current point back to the sub-path's starting
+    /// point, so that a relative command following `close` is computed
+    /// from there rather than from wherever the sub-path happened to end.
+    ///
     /// [`Path`]: struct.Path.html
     #[inline]
     pub fn close(&mut self) {
-        self.raw.close()
+        self.raw.close();
+        self.current = self.subpath_start;
     }
 
     /// Builds the [`Path`] of this [`Builder`].
@@ -175,6 +470,345 @@ impl Builder {
     pub fn build(self) -> Path {
         Path {
             raw: self.raw.build(),
+            fill_rule: self.fill_rule,
+        }
+    }
+
+    /// Parses an SVG path `d` attribute string and appends the segments it
+    /// describes to the [`Path`].
+    ///
+    /// The full `M/m L/l H/h V/v C/c S/s Q/q T/t A/a Z/z` command grammar is
+    /// supported, including implicit repeated commands (extra coordinate
+    /// pairs following a command letter reuse it, with `M`/`m` falling back
+    /// to `L`/`l`) and compact number forms such as `1.5.5` or `-.3`.
+    ///
+    /// [`Path`]: struct.Path.html
+    pub fn svg_path(&mut self, d: &str) -> Result<(), Error> {
+        svg::parse(d, self)
+    }
+}
+
+/// The rule used to determine the interior of a [`Path`], i.e. which
+/// regions of a self-intersecting path or a path made up of several
+/// overlapping sub-paths are filled.
+///
+/// [`Path`]: struct.Path.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is interior if a ray cast from it crosses a non-zero
+    /// number of sub-path edges, counting direction.
+    ///
+    /// This is the default and draws a hole only when an inner sub-path
+    /// winds in the opposite direction of the outer one.
+    NonZero,
+    /// A point is interior if a ray cast from it crosses an odd number
+    /// of sub-path edges, regardless of direction.
+    EvenOdd,
+}
+
+impl Default for FillRule {
+    fn default() -> Self {
+        FillRule::NonZero
+    }
+}
+
+/// An error produced while parsing an SVG path data string with
+/// [`Builder::svg_path`].
+///
+/// [`Builder::svg_path`]: struct.Builder.html#method.svg_path
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// A command letter or a number was expected, but something else was
+    /// found.
+    UnexpectedToken(char),
+    /// The input ended before a command's arguments were fully provided.
+    UnexpectedEnd,
+    /// A number could not be parsed.
+    InvalidNumber(String),
+    /// An elliptical arc flag was not `0` or `1`.
+    InvalidFlag(char),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnexpectedToken(c) => {
+                write!(f, "unexpected character `{}` in SVG path data", c)
+            }
+            Error::UnexpectedEnd => {
+                write!(f, "unexpected end of SVG path data")
+            }
+            Error::InvalidNumber(number) => {
+                write!(f, "invalid number `{}` in SVG path data", number)
+            }
+            Error::InvalidFlag(c) => write!(
+                f,
+                "invalid arc flag `{}` in SVG path data, expected `0` or `1`",
+                c
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+mod svg {
+    use super::{Builder, Error};
+
+    use iced_native::{Point, Vector};
+
+    /// Runs the command grammar described in [`Builder::svg_path`] against
+    /// `builder`.
+    ///
+    /// [`Builder::svg_path`]: struct.Builder.html#method.svg_path
+    pub fn parse(d: &str, builder: &mut Builder) -> Result<(), Error> {
+        let mut cursor = Cursor::new(d);
+        let mut command = None;
+
+        // The control point of the previous `C`/`S`/`Q`/`T` command, used to
+        // reflect the control point of a following `S`/`T` command. The
+        // tagged family (`'C'` or `'Q'`) determines whether it applies.
+        let mut last_control: Option<(char, Point)> = None;
+
+        loop {
+            cursor.skip_separators();
+
+            if cursor.is_at_end() {
+                break;
+            }
+
+            if let Some(c) = cursor.peek() {
+                if c.is_ascii_alphabetic() {
+                    let _ = cursor.advance();
+                    command = Some(c);
+                }
+            }
+
+            let c = command
+                .ok_or_else(|| Error::UnexpectedToken(cursor.peek().unwrap_or(' ')))?;
+
+            let current = current_point(builder);
+
+            match c {
+                'Z' | 'z' => {
+                    builder.close();
+                    last_control = None;
+                    command = None;
+                }
+                'M' | 'm' => {
+                    let point = cursor.point(is_relative(c), current)?;
+                    builder.move_to(point);
+                    last_control = None;
+
+                    // Any further coordinate pairs are implicit `L`/`l`.
+                    command = Some(if c == 'M' { 'L' } else { 'l' });
+                }
+                'L' | 'l' => {
+                    let point = cursor.point(is_relative(c), current)?;
+                    builder.line_to(point);
+                    last_control = None;
+                }
+                'H' | 'h' => {
+                    let x = cursor.number()?;
+                    let x = if is_relative(c) { current.x + x } else { x };
+                    builder.line_to(Point::new(x, current.y));
+                    last_control = None;
+                }
+                'V' | 'v' => {
+                    let y = cursor.number()?;
+                    let y = if is_relative(c) { current.y + y } else { y };
+                    builder.line_to(Point::new(current.x, y));
+                    last_control = None;
+                }
+                'C' | 'c' => {
+                    let control_a = cursor.point(is_relative(c), current)?;
+                    let control_b = cursor.point(is_relative(c), current)?;
+                    let to = cursor.point(is_relative(c), current)?;
+
+                    builder.bezier_curve_to(control_a, control_b, to);
+                    last_control = Some(('C', control_b));
+                }
+                'S' | 's' => {
+                    let control_a = reflect(last_control, 'C', current);
+                    let control_b = cursor.point(is_relative(c), current)?;
+                    let to = cursor.point(is_relative(c), current)?;
+
+                    builder.bezier_curve_to(control_a, control_b, to);
+                    last_control = Some(('C', control_b));
+                }
+                'Q' | 'q' => {
+                    let control = cursor.point(is_relative(c), current)?;
+                    let to = cursor.point(is_relative(c), current)?;
+
+                    builder.quadratic_curve_to(control, to);
+                    last_control = Some(('Q', control));
+                }
+                'T' | 't' => {
+                    let control = reflect(last_control, 'Q', current);
+                    let to = cursor.point(is_relative(c), current)?;
+
+                    builder.quadratic_curve_to(control, to);
+                    last_control = Some(('Q', control));
+                }
+                'A' | 'a' => {
+                    let radii = Vector::new(cursor.number()?, cursor.number()?);
+                    let x_rotation = cursor.number()?.to_radians();
+                    let large_arc = cursor.flag()?;
+                    let sweep = cursor.flag()?;
+                    let to = cursor.point(is_relative(c), current)?;
+
+                    builder
+                        .elliptical_arc_to(radii, x_rotation, large_arc, sweep, to);
+                    last_control = None;
+                }
+                _ => return Err(Error::UnexpectedToken(c)),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_relative(command: char) -> bool {
+        command.is_ascii_lowercase()
+    }
+
+    fn reflect(
+        last_control: Option<(char, Point)>,
+        family: char,
+        current: Point,
+    ) -> Point {
+        match last_control {
+            Some((kind, point)) if kind == family => {
+                Point::new(2.0 * current.x - point.x, 2.0 * current.y - point.y)
+            }
+            _ => current,
+        }
+    }
+
+    fn current_point(builder: &Builder) -> Point {
+        builder.current_position()
+    }
+
+    /// A cursor over the characters of an SVG path data string.
+    struct Cursor {
+        chars: Vec<char>,
+        position: usize,
+    }
+
+    impl Cursor {
+        fn new(d: &str) -> Self {
+            Cursor {
+                chars: d.chars().collect(),
+                position: 0,
+            }
+        }
+
+        fn peek(&self) -> Option<char> {
+            self.chars.get(self.position).copied()
+        }
+
+        fn advance(&mut self) -> Option<char> {
+            let c = self.peek();
+
+            if c.is_some() {
+                self.position += 1;
+            }
+
+            c
+        }
+
+        fn is_at_end(&self) -> bool {
+            self.position >= self.chars.len()
+        }
+
+        fn skip_separators(&mut self) {
+            while let Some(c) = self.peek() {
+                if c.is_whitespace() || c == ',' {
+                    self.position += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        fn point(&mut self, relative: bool, current: Point) -> Result<Point, Error> {
+            let x = self.number()?;
+            let y = self.number()?;
+
+            Ok(if relative {
+                Point::new(current.x + x, current.y + y)
+            } else {
+                Point::new(x, y)
+            })
+        }
+
+        /// Parses a single number, accepting compact forms with no
+        /// separator between consecutive values (e.g. `1.5.5` is `1.5`
+        /// followed by `.5`, and `-.3` is `-0.3`).
+        fn number(&mut self) -> Result<f32, Error> {
+            self.skip_separators();
+
+            let start = self.position;
+
+            if matches!(self.peek(), Some('+') | Some('-')) {
+                self.position += 1;
+            }
+
+            let mut has_digits = false;
+
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.position += 1;
+                has_digits = true;
+            }
+
+            if self.peek() == Some('.') {
+                self.position += 1;
+
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                    self.position += 1;
+                    has_digits = true;
+                }
+            }
+
+            if !has_digits {
+                return Err(self
+                    .peek()
+                    .map_or(Error::UnexpectedEnd, Error::UnexpectedToken));
+            }
+
+            if matches!(self.peek(), Some('e') | Some('E')) {
+                let mark = self.position;
+                self.position += 1;
+
+                if matches!(self.peek(), Some('+') | Some('-')) {
+                    self.position += 1;
+                }
+
+                if matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                    while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                        self.position += 1;
+                    }
+                } else {
+                    self.position = mark;
+                }
+            }
+
+            let raw: String = self.chars[start..self.position].iter().collect();
+
+            raw.parse::<f32>().map_err(|_| Error::InvalidNumber(raw))
+        }
+
+        /// Parses a single arc flag (`0` or `1`), which may be packed
+        /// directly against the following number with no separator.
+        fn flag(&mut self) -> Result<bool, Error> {
+            self.skip_separators();
+
+            match self.advance() {
+                Some('0') => Ok(false),
+                Some('1') => Ok(true),
+                Some(c) => Err(Error::InvalidFlag(c)),
+                None => Err(Error::UnexpectedEnd),
+            }
         }
     }
 }
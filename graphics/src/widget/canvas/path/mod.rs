@@ -0,0 +1,64 @@
+//! Build and draw vector paths.
+mod builder;
+
+pub use builder::{Builder, Error, FillRule};
+
+/// An immutable set of points and segments that can be built into any
+/// shape and then filled or stroked.
+#[derive(Debug, Clone)]
+pub struct Path {
+    raw: lyon::path::Path,
+    fill_rule: FillRule,
+}
+
+impl Path {
+    /// Creates a new [`Path`] with the provided closure.
+    ///
+    /// Use the [`Builder`] to configure your [`Path`].
+    ///
+    /// [`Builder`]: struct.Builder.html
+    pub fn new(f: impl FnOnce(&mut Builder)) -> Self {
+        let mut builder = Builder::new();
+
+        f(&mut builder);
+
+        builder.build()
+    }
+
+    /// Returns the [`FillRule`] chosen for this [`Path`] with
+    /// [`Builder::fill_rule`].
+    ///
+    /// The tessellation stage consults this to decide which regions of a
+    /// self-intersecting path, or a path made up of overlapping sub-paths,
+    /// are filled.
+    ///
+    /// [`FillRule`]: enum.FillRule.html
+    /// [`Builder::fill_rule`]: struct.Builder.html#method.fill_rule
+    pub fn fill_rule(&self) -> FillRule {
+        self.fill_rule
+    }
+
+    #[inline]
+    pub(crate) fn raw(&self) -> &lyon::path::Path {
+        &self.raw
+    }
+
+    /// Returns the [`lyon_tessellation::FillOptions`] a tessellator should
+    /// use to fill this [`Path`], with [`fill_rule`] already applied.
+    ///
+    /// [`fill_rule`]: #method.fill_rule
+    #[inline]
+    pub(crate) fn fill_options(&self) -> lyon_tessellation::FillOptions {
+        lyon_tessellation::FillOptions::default()
+            .with_fill_rule(self.fill_rule.into())
+    }
+}
+
+impl From<FillRule> for lyon_tessellation::FillRule {
+    fn from(fill_rule: FillRule) -> lyon_tessellation::FillRule {
+        match fill_rule {
+            FillRule::NonZero => lyon_tessellation::FillRule::NonZero,
+            FillRule::EvenOdd => lyon_tessellation::FillRule::EvenOdd,
+        }
+    }
+}